@@ -0,0 +1,137 @@
+use bytes::{Bytes, BytesMut};
+use tokio_util::codec::Decoder;
+
+/// A decoder for handling Consistent Overhead Byte Stuffing (COBS) framing.
+///
+/// This is the inverse of the COBS encoder: it scans forward to the
+/// next `0x00` delimiter -- which can always be found, since a well-formed encoded frame never
+/// contains a literal zero before it -- then walks the code/run pairs within that frame, inserting
+/// a zero byte after each run except where the run's code was `0xFF` or the run is the last one in
+/// the frame. Because resynchronization is
+/// just "find the next zero byte", a corrupt or truncated frame can never make this decoder hang
+/// or run away consuming unbounded data; it simply reports that one frame as invalid and resumes
+/// at the next delimiter.
+#[derive(Debug, Clone, Default)]
+pub struct CobsDecoder;
+
+impl CobsDecoder {
+    /// Creates a new `CobsDecoder`.
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Decoder for CobsDecoder {
+    type Item = Bytes;
+    type Error = std::io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        let Some(delimiter_pos) = src.iter().position(|&byte| byte == 0) else {
+            return Ok(None);
+        };
+
+        let frame = src.split_to(delimiter_pos + 1);
+        let encoded = &frame[..frame.len() - 1];
+
+        let mut decoded = BytesMut::with_capacity(encoded.len());
+        let mut i = 0;
+        while i < encoded.len() {
+            let code = encoded[i];
+            if code == 0 {
+                return Err(invalid_data("COBS frame contained a literal zero code byte"));
+            }
+            i += 1;
+
+            let run_len = usize::from(code) - 1;
+            let Some(run) = encoded.get(i..i + run_len) else {
+                return Err(invalid_data("COBS frame's code byte ran past the end of the frame"));
+            };
+            decoded.extend_from_slice(run);
+            i += run_len;
+
+            // A code of 0xFF means the run was exactly 254 bytes with no implied trailing zero.
+            // Any shorter run implies a zero byte, unless it's the final run in the frame, in
+            // which case the frame's own delimiter plays that role instead.
+            if code != 0xFF && i < encoded.len() {
+                decoded.extend_from_slice(&[0]);
+            }
+        }
+
+        Ok(Some(decoded.freeze()))
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        match self.decode(src)? {
+            Some(frame) => Ok(Some(frame)),
+            None if src.is_empty() => Ok(None),
+            // No terminating zero byte ever showed up -- the stream ended mid-frame.
+            None => Err(std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "bytes remaining on stream",
+            )),
+        }
+    }
+}
+
+fn invalid_data(message: &'static str) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::framing::CobsEncoder;
+    use tokio_util::codec::Encoder as _;
+
+    fn round_trip(input: &[u8]) -> Bytes {
+        let mut encoded = BytesMut::from(input);
+        CobsEncoder::new().encode((), &mut encoded).unwrap();
+
+        let frame = CobsDecoder::new()
+            .decode(&mut encoded)
+            .unwrap()
+            .expect("a full frame should decode in one pass");
+        assert!(encoded.is_empty(), "the whole frame should have been consumed");
+        frame
+    }
+
+    #[test]
+    fn round_trips_a_payload_with_embedded_zeros() {
+        let input = b"hello\0world\0\0!";
+        assert_eq!(round_trip(input), Bytes::from_static(input));
+    }
+
+    #[test]
+    fn round_trips_an_empty_payload() {
+        assert_eq!(round_trip(b""), Bytes::new());
+    }
+
+    #[test]
+    fn round_trips_a_payload_longer_than_one_run() {
+        let input: Vec<u8> = (0..600).map(|i| (i % 251) as u8).collect();
+        assert_eq!(round_trip(&input), Bytes::from(input));
+    }
+
+    #[test]
+    fn resyncs_at_the_next_delimiter_after_a_corrupt_frame() {
+        let mut buffer = BytesMut::new();
+        // A code byte claiming a run longer than the data that follows, then a valid frame.
+        buffer.extend_from_slice(&[0xFE, b'a', 0]);
+        let mut good = BytesMut::from(&b"ok"[..]);
+        CobsEncoder::new().encode((), &mut good).unwrap();
+        buffer.extend_from_slice(&good);
+
+        let mut decoder = CobsDecoder::new();
+        assert!(decoder.decode(&mut buffer).is_err());
+        assert_eq!(decoder.decode(&mut buffer).unwrap(), Some(Bytes::from_static(b"ok")));
+    }
+
+    #[test]
+    fn decode_eof_reports_a_missing_delimiter_as_unexpected_eof() {
+        let mut buffer = BytesMut::from(&b"hello"[..]);
+        let error = CobsDecoder::new()
+            .decode_eof(&mut buffer)
+            .expect_err("a frame with no terminating zero byte at EOF should be an error");
+        assert_eq!(error.kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+}
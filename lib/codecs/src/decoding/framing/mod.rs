@@ -0,0 +1,388 @@
+mod cobs;
+
+use bytes::{Buf, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use tokio_util::codec::Decoder;
+
+pub use cobs::CobsDecoder;
+
+/// Options for the character-delimited decoder.
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct CharacterDelimitedDecoderOptions {
+    /// The character that delimits frames.
+    pub delimiter: u8,
+
+    /// The maximum frame length, in bytes, before the decoder gives up looking for a delimiter.
+    #[serde(default)]
+    pub max_length: Option<usize>,
+}
+
+/// Options for the newline-delimited decoder.
+#[derive(Debug, Clone, Eq, PartialEq, Default, Deserialize, Serialize)]
+pub struct NewlineDelimitedDecoderOptions {
+    /// The maximum frame length, in bytes, before the decoder gives up looking for a newline.
+    #[serde(default)]
+    pub max_length: Option<usize>,
+}
+
+/// Options for the octet-counting decoder.
+#[derive(Debug, Clone, Eq, PartialEq, Default, Deserialize, Serialize)]
+pub struct OctetCountingDecoderOptions {
+    /// The maximum frame length, in bytes, that the decoder will allow.
+    #[serde(default)]
+    pub max_length: Option<usize>,
+}
+
+/// Framing configuration for the decoding side of a codec.
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum FramingConfig {
+    /// Bytes are passed through as-is, with no delimiter between frames.
+    Bytes,
+
+    /// Frames are separated by a single configured character.
+    CharacterDelimited {
+        /// Options for the character-delimited decoder.
+        character_delimited: CharacterDelimitedDecoderOptions,
+    },
+
+    /// Frames are prefixed by their length, as a 32-bit big-endian integer.
+    LengthDelimited,
+
+    /// Frames are separated by a newline (`\n`) character.
+    NewlineDelimited {
+        /// Options for the newline-delimited decoder.
+        #[serde(default)]
+        newline_delimited: NewlineDelimitedDecoderOptions,
+    },
+
+    /// Frames are prefixed with their length as an ASCII decimal integer, followed by a space.
+    OctetCounting {
+        /// Options for the octet-counting decoder.
+        #[serde(default)]
+        octet_counting: OctetCountingDecoderOptions,
+    },
+
+    /// Frames are encoded with Consistent Overhead Byte Stuffing, terminated by a zero byte.
+    Cobs,
+}
+
+impl FramingConfig {
+    /// Builds a [`Framer`] from this configuration.
+    pub fn build(self) -> Framer {
+        match self {
+            Self::Bytes => Framer::Bytes(BytesDecoder),
+            Self::CharacterDelimited { character_delimited } => {
+                Framer::CharacterDelimited(CharacterDelimitedDecoder::new(
+                    character_delimited.delimiter,
+                    character_delimited.max_length,
+                ))
+            }
+            Self::LengthDelimited => Framer::LengthDelimited(LengthDelimitedDecoder::default()),
+            Self::NewlineDelimited { newline_delimited } => {
+                Framer::NewlineDelimited(NewlineDelimitedDecoder::new(newline_delimited.max_length))
+            }
+            Self::OctetCounting { octet_counting } => {
+                Framer::OctetCounting(OctetCountingDecoder::new(octet_counting.max_length))
+            }
+            Self::Cobs => Framer::Cobs(CobsDecoder::new()),
+        }
+    }
+}
+
+/// A framer for the decoding side of a codec, dispatching to the method configured by
+/// [`FramingConfig`].
+#[derive(Debug, Clone)]
+pub enum Framer {
+    /// Reads frames through as-is, with no delimiter between them.
+    Bytes(BytesDecoder),
+    /// Reads frames separated by a single configured character.
+    CharacterDelimited(CharacterDelimitedDecoder),
+    /// Reads frames prefixed by their length, as a 32-bit big-endian integer.
+    LengthDelimited(LengthDelimitedDecoder),
+    /// Reads frames separated by a newline (`\n`) character.
+    NewlineDelimited(NewlineDelimitedDecoder),
+    /// Reads frames prefixed with their length as an ASCII decimal integer, per RFC 6587.
+    OctetCounting(OctetCountingDecoder),
+    /// Reads frames encoded with Consistent Overhead Byte Stuffing.
+    Cobs(CobsDecoder),
+}
+
+impl Decoder for Framer {
+    type Item = Bytes;
+    type Error = std::io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        match self {
+            Self::Bytes(framer) => framer.decode(src),
+            Self::CharacterDelimited(framer) => framer.decode(src),
+            Self::LengthDelimited(framer) => framer.decode(src),
+            Self::NewlineDelimited(framer) => framer.decode(src),
+            Self::OctetCounting(framer) => framer.decode(src),
+            Self::Cobs(framer) => framer.decode(src),
+        }
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        match self {
+            Self::Bytes(framer) => framer.decode_eof(src),
+            Self::CharacterDelimited(framer) => framer.decode_eof(src),
+            Self::LengthDelimited(framer) => framer.decode_eof(src),
+            Self::NewlineDelimited(framer) => framer.decode_eof(src),
+            Self::OctetCounting(framer) => framer.decode_eof(src),
+            Self::Cobs(framer) => framer.decode_eof(src),
+        }
+    }
+}
+
+/// Reads frames through as-is, treating all currently-buffered bytes as one frame.
+#[derive(Debug, Clone, Default)]
+pub struct BytesDecoder;
+
+impl Decoder for BytesDecoder {
+    type Item = Bytes;
+    type Error = std::io::Error;
+
+    fn decode(&mut self, _src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        Ok(None)
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        if src.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(src.split().freeze()))
+        }
+    }
+}
+
+/// Reads frames separated by a single configured character.
+#[derive(Debug, Clone)]
+pub struct CharacterDelimitedDecoder {
+    delimiter: u8,
+    max_length: usize,
+}
+
+impl CharacterDelimitedDecoder {
+    /// Creates a new `CharacterDelimitedDecoder` with the given delimiter and optional maximum
+    /// frame length.
+    pub fn new(delimiter: u8, max_length: Option<usize>) -> Self {
+        Self {
+            delimiter,
+            max_length: max_length.unwrap_or(usize::MAX),
+        }
+    }
+}
+
+impl Decoder for CharacterDelimitedDecoder {
+    type Item = Bytes;
+    type Error = std::io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        match src.iter().position(|&byte| byte == self.delimiter) {
+            Some(pos) if pos <= self.max_length => {
+                let frame = src.split_to(pos);
+                src.advance(1);
+                Ok(Some(frame.freeze()))
+            }
+            Some(pos) => Err(frame_too_long(pos, self.max_length)),
+            None => Ok(None),
+        }
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        truncated_if_bytes_remain(self.decode(src)?, src)
+    }
+}
+
+/// Reads frames prefixed by their length, as a 32-bit big-endian integer.
+#[derive(Debug, Clone, Default)]
+pub struct LengthDelimitedDecoder {
+    inner: tokio_util::codec::LengthDelimitedCodec,
+}
+
+impl Decoder for LengthDelimitedDecoder {
+    type Item = Bytes;
+    type Error = std::io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        Ok(self.inner.decode(src)?.map(BytesMut::freeze))
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        truncated_if_bytes_remain(self.decode(src)?, src)
+    }
+}
+
+/// Reads frames separated by a newline (`\n`) character.
+#[derive(Debug, Clone)]
+pub struct NewlineDelimitedDecoder {
+    inner: CharacterDelimitedDecoder,
+}
+
+impl NewlineDelimitedDecoder {
+    /// Creates a new `NewlineDelimitedDecoder` with an optional maximum frame length.
+    pub fn new(max_length: Option<usize>) -> Self {
+        Self {
+            inner: CharacterDelimitedDecoder::new(b'\n', max_length),
+        }
+    }
+}
+
+impl Decoder for NewlineDelimitedDecoder {
+    type Item = Bytes;
+    type Error = std::io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        self.inner.decode(src)
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        truncated_if_bytes_remain(self.decode(src)?, src)
+    }
+}
+
+/// Reads frames prefixed with their length as an ASCII decimal integer, followed by a space, per
+/// RFC 6587. This is the inverse of the octet-counting encoder.
+#[derive(Debug, Clone, Default)]
+pub struct OctetCountingDecoder {
+    max_length: usize,
+}
+
+impl OctetCountingDecoder {
+    /// Creates a new `OctetCountingDecoder` with an optional maximum frame length.
+    pub fn new(max_length: Option<usize>) -> Self {
+        Self {
+            max_length: max_length.unwrap_or(usize::MAX),
+        }
+    }
+}
+
+impl Decoder for OctetCountingDecoder {
+    type Item = Bytes;
+    type Error = std::io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        let Some(space_pos) = src.iter().position(|&byte| byte == b' ') else {
+            if src.len() > 20 {
+                return Err(invalid_data("octet count prefix was never terminated by a space"));
+            }
+            return Ok(None);
+        };
+
+        let len: usize = std::str::from_utf8(&src[..space_pos])
+            .ok()
+            .and_then(|digits| digits.parse().ok())
+            .ok_or_else(|| invalid_data("octet count prefix was not a valid decimal integer"))?;
+
+        if len > self.max_length {
+            return Err(frame_too_long(len, self.max_length));
+        }
+
+        let frame_end = space_pos + 1 + len;
+        if src.len() < frame_end {
+            return Ok(None);
+        }
+
+        src.advance(space_pos + 1);
+        Ok(Some(src.split_to(len).freeze()))
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        truncated_if_bytes_remain(self.decode(src)?, src)
+    }
+}
+
+fn frame_too_long(len: usize, max_length: usize) -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::InvalidData,
+        format!("frame of length {len} exceeds the maximum of {max_length}"),
+    )
+}
+
+fn invalid_data(message: &'static str) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, message)
+}
+
+/// Turns a `decode` result of "no frame yet" into an error when bytes remain unconsumed.
+///
+/// `tokio_util`'s default `decode_eof` reports this case as `ErrorKind::Other`, which is
+/// indistinguishable from any other decode failure; callers here (see
+/// `components::validation::resources::classify_decode_error`) need to tell a genuinely truncated
+/// frame apart from a malformed one, so every framer in this module reports it as
+/// `ErrorKind::UnexpectedEof` instead.
+fn truncated_if_bytes_remain<T>(
+    frame: Option<T>,
+    src: &BytesMut,
+) -> Result<Option<T>, std::io::Error> {
+    match frame {
+        Some(frame) => Ok(Some(frame)),
+        None if src.is_empty() => Ok(None),
+        None => Err(std::io::Error::new(
+            std::io::ErrorKind::UnexpectedEof,
+            "bytes remaining on stream",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::framing::OctetCountingEncoder;
+    use tokio_util::codec::Encoder as _;
+
+    fn round_trip(input: &[u8]) -> Bytes {
+        let mut encoded = BytesMut::from(input);
+        OctetCountingEncoder::new().encode((), &mut encoded).unwrap();
+
+        let frame = OctetCountingDecoder::default()
+            .decode(&mut encoded)
+            .unwrap()
+            .expect("a full frame should decode in one pass");
+        assert!(encoded.is_empty(), "the whole frame should have been consumed");
+        frame
+    }
+
+    #[test]
+    fn round_trips_a_payload() {
+        assert_eq!(round_trip(b"hello world"), Bytes::from_static(b"hello world"));
+    }
+
+    #[test]
+    fn round_trips_an_empty_payload() {
+        assert_eq!(round_trip(b""), Bytes::new());
+    }
+
+    #[test]
+    fn waits_for_more_data_when_the_frame_is_incomplete() {
+        let mut buffer = BytesMut::from(&b"5 hel"[..]);
+        assert_eq!(OctetCountingDecoder::default().decode(&mut buffer).unwrap(), None);
+    }
+
+    #[test]
+    fn decode_eof_reports_a_short_frame_as_unexpected_eof() {
+        let mut buffer = BytesMut::from(&b"5 hel"[..]);
+        let error = OctetCountingDecoder::default()
+            .decode_eof(&mut buffer)
+            .expect_err("a frame missing bytes at EOF should be an error");
+        assert_eq!(error.kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn decode_eof_reports_a_missing_delimiter_as_unexpected_eof() {
+        let mut buffer = BytesMut::from(&b"hello"[..]);
+        let error = CharacterDelimitedDecoder::new(b'\n', None)
+            .decode_eof(&mut buffer)
+            .expect_err("a frame missing its delimiter at EOF should be an error");
+        assert_eq!(error.kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn decode_eof_is_ok_when_the_buffer_is_fully_consumed() {
+        let mut buffer = BytesMut::new();
+        assert_eq!(
+            OctetCountingDecoder::default().decode_eof(&mut buffer).unwrap(),
+            None
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use bytes::{BufMut, BytesMut};
+use tokio_util::codec::Encoder;
+
+/// An encoder for handling Consistent Overhead Byte Stuffing (COBS) framing.
+///
+/// COBS replaces every zero byte in the message with a "code" byte describing how many
+/// non-zero bytes follow until the next zero (or the end of the message), so the encoded frame
+/// never contains a literal zero byte. A single `0x00` then terminates the frame: since the
+/// decoder knows zero can only ever appear as that final delimiter, it can always resynchronize
+/// by scanning forward to the next zero byte, even after corrupted or truncated data.
+///
+/// A run of 254 consecutive non-zero bytes is emitted with code `0xFF` and, uniquely, does *not*
+/// imply a following zero byte -- this avoids ambiguity between "254 bytes then a zero" and
+/// "254 bytes then more data".
+#[derive(Debug, Clone, Default)]
+pub struct CobsEncoder;
+
+impl CobsEncoder {
+    /// Creates a new `CobsEncoder`.
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Encoder<()> for CobsEncoder {
+    type Error = std::io::Error;
+
+    fn encode(&mut self, _item: (), buffer: &mut BytesMut) -> Result<(), Self::Error> {
+        let input = buffer.split();
+
+        // Reserve room for the worst case (one extra code byte per 254 non-zero bytes, plus the
+        // leading code byte and the final delimiter).
+        let mut output = BytesMut::with_capacity(input.len() + input.len() / 254 + 2);
+
+        let mut code_pos = 0usize;
+        let mut code = 1u8;
+        output.put_u8(0); // Placeholder; patched once the run length is known.
+
+        for (index, &byte) in input.iter().enumerate() {
+            if byte == 0 {
+                output[code_pos] = code;
+                code_pos = output.len();
+                code = 1;
+                output.put_u8(0); // Placeholder for the next run.
+            } else {
+                output.put_u8(byte);
+                code += 1;
+
+                // A full run at the very end of the input needs no further code byte.
+                if code == 0xFF && index + 1 < input.len() {
+                    output[code_pos] = code;
+                    code_pos = output.len();
+                    code = 1;
+                    output.put_u8(0); // Placeholder for the next run.
+                }
+            }
+        }
+
+        output[code_pos] = code;
+        output.put_u8(0); // Frame delimiter.
+
+        buffer.unsplit(output);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(input: &[u8]) -> BytesMut {
+        let mut buffer = BytesMut::from(input);
+        CobsEncoder::new().encode((), &mut buffer).unwrap();
+        buffer
+    }
+
+    #[test]
+    fn never_emits_a_zero_byte_before_the_delimiter() {
+        let encoded = encode(b"hello\0world");
+        assert_eq!(encoded.last(), Some(&0));
+        assert!(!encoded[..encoded.len() - 1].contains(&0));
+    }
+
+    #[test]
+    fn empty_payload_encodes_to_bare_delimiter() {
+        assert_eq!(encode(b""), &b"\x01\x00"[..]);
+    }
+
+    #[test]
+    fn run_of_exactly_254_bytes_uses_0xff_with_no_implied_zero() {
+        let input = vec![b'a'; 254];
+        let encoded = encode(&input);
+        assert_eq!(encoded[0], 0xFF);
+        assert_eq!(&encoded[1..255], &input[..]);
+        // No implied zero: the next byte is the frame delimiter, not another code byte.
+        assert_eq!(encoded[255], 0);
+        assert_eq!(encoded.len(), 256);
+    }
+}
@@ -0,0 +1,155 @@
+mod cobs;
+mod octet_counting;
+
+use bytes::{BufMut, BytesMut};
+use serde::{Deserialize, Serialize};
+use tokio_util::codec::Encoder;
+
+pub use cobs::CobsEncoder;
+pub use octet_counting::OctetCountingEncoder;
+
+/// Options for the character-delimited encoder.
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct CharacterDelimitedEncoderOptions {
+    /// The character used to delimit encoded frames.
+    pub delimiter: u8,
+}
+
+/// Framing configuration for the encoding side of a codec.
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum FramingConfig {
+    /// Bytes are passed through as-is, with no delimiter between frames.
+    Bytes,
+
+    /// Frames are separated by a single configured character.
+    CharacterDelimited {
+        /// Options for the character-delimited encoder.
+        character_delimited: CharacterDelimitedEncoderOptions,
+    },
+
+    /// Frames are prefixed by their length, as a 32-bit big-endian integer.
+    LengthDelimited,
+
+    /// Frames are separated by a newline (`\n`) character.
+    NewlineDelimited,
+
+    /// Frames are prefixed with their length as an ASCII decimal integer, followed by a space.
+    OctetCounting,
+
+    /// Frames are encoded with Consistent Overhead Byte Stuffing, terminated by a zero byte.
+    Cobs,
+}
+
+impl FramingConfig {
+    /// Builds a [`Framer`] from this configuration.
+    pub fn build(self) -> Framer {
+        match self {
+            Self::Bytes => Framer::Bytes(BytesEncoder),
+            Self::CharacterDelimited { character_delimited } => {
+                Framer::CharacterDelimited(CharacterDelimitedEncoder::new(
+                    character_delimited.delimiter,
+                ))
+            }
+            Self::LengthDelimited => Framer::LengthDelimited(LengthDelimitedEncoder),
+            Self::NewlineDelimited => Framer::NewlineDelimited(NewlineDelimitedEncoder),
+            Self::OctetCounting => Framer::OctetCounting(OctetCountingEncoder::new()),
+            Self::Cobs => Framer::Cobs(CobsEncoder::new()),
+        }
+    }
+}
+
+/// A framer for the encoding side of a codec, dispatching to the method configured by
+/// [`FramingConfig`].
+#[derive(Debug, Clone)]
+pub enum Framer {
+    /// Writes frames through as-is, with no delimiter between them.
+    Bytes(BytesEncoder),
+    /// Writes frames separated by a single configured character.
+    CharacterDelimited(CharacterDelimitedEncoder),
+    /// Writes frames prefixed by their length, as a 32-bit big-endian integer.
+    LengthDelimited(LengthDelimitedEncoder),
+    /// Writes frames separated by a newline (`\n`) character.
+    NewlineDelimited(NewlineDelimitedEncoder),
+    /// Writes frames prefixed with their length as an ASCII decimal integer, per RFC 6587.
+    OctetCounting(OctetCountingEncoder),
+    /// Writes frames encoded with Consistent Overhead Byte Stuffing.
+    Cobs(CobsEncoder),
+}
+
+impl Encoder<()> for Framer {
+    type Error = std::io::Error;
+
+    fn encode(&mut self, item: (), buffer: &mut BytesMut) -> Result<(), Self::Error> {
+        match self {
+            Self::Bytes(framer) => framer.encode(item, buffer),
+            Self::CharacterDelimited(framer) => framer.encode(item, buffer),
+            Self::LengthDelimited(framer) => framer.encode(item, buffer),
+            Self::NewlineDelimited(framer) => framer.encode(item, buffer),
+            Self::OctetCounting(framer) => framer.encode(item, buffer),
+            Self::Cobs(framer) => framer.encode(item, buffer),
+        }
+    }
+}
+
+/// Passes frames through as-is, with no delimiter between them.
+#[derive(Debug, Clone, Default)]
+pub struct BytesEncoder;
+
+impl Encoder<()> for BytesEncoder {
+    type Error = std::io::Error;
+
+    fn encode(&mut self, _item: (), _buffer: &mut BytesMut) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// Writes frames separated by a single configured character.
+#[derive(Debug, Clone)]
+pub struct CharacterDelimitedEncoder {
+    delimiter: u8,
+}
+
+impl CharacterDelimitedEncoder {
+    /// Creates a new `CharacterDelimitedEncoder` with the given delimiter.
+    pub const fn new(delimiter: u8) -> Self {
+        Self { delimiter }
+    }
+}
+
+impl Encoder<()> for CharacterDelimitedEncoder {
+    type Error = std::io::Error;
+
+    fn encode(&mut self, _item: (), buffer: &mut BytesMut) -> Result<(), Self::Error> {
+        buffer.put_u8(self.delimiter);
+        Ok(())
+    }
+}
+
+/// Writes frames prefixed by their length, as a 32-bit big-endian integer.
+#[derive(Debug, Clone, Default)]
+pub struct LengthDelimitedEncoder;
+
+impl Encoder<()> for LengthDelimitedEncoder {
+    type Error = std::io::Error;
+
+    fn encode(&mut self, _item: (), buffer: &mut BytesMut) -> Result<(), Self::Error> {
+        let message = buffer.split();
+        buffer.put_u32(message.len() as u32);
+        buffer.unsplit(message);
+        Ok(())
+    }
+}
+
+/// Writes frames separated by a newline (`\n`) character.
+#[derive(Debug, Clone, Default)]
+pub struct NewlineDelimitedEncoder;
+
+impl Encoder<()> for NewlineDelimitedEncoder {
+    type Error = std::io::Error;
+
+    fn encode(&mut self, _item: (), buffer: &mut BytesMut) -> Result<(), Self::Error> {
+        buffer.put_u8(b'\n');
+        Ok(())
+    }
+}
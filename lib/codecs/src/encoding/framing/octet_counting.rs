@@ -0,0 +1,61 @@
+use bytes::{BufMut, BytesMut};
+use tokio_util::codec::Encoder;
+
+/// An encoder for handling octet counting framing.
+///
+/// This framer writes the serialized message's length, as an ASCII decimal integer, followed by a
+/// single space (0x20), followed by the message bytes themselves, with no trailing delimiter. This
+/// is the encoding-side counterpart to the octet-counting framing described in RFC 6587: the
+/// decoder knows exactly how many bytes make up the next frame by reading the leading count, so it
+/// never has to scan for a delimiter that could also appear inside the message body.
+#[derive(Debug, Clone, Default)]
+pub struct OctetCountingEncoder;
+
+impl OctetCountingEncoder {
+    /// Creates a new `OctetCountingEncoder`.
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Encoder<()> for OctetCountingEncoder {
+    type Error = std::io::Error;
+
+    fn encode(&mut self, _item: (), buffer: &mut BytesMut) -> Result<(), Self::Error> {
+        // The buffer already holds the serialized message; measure it before we prepend anything.
+        let prefix = format!("{} ", buffer.len());
+
+        let message = buffer.split();
+        buffer.put_slice(prefix.as_bytes());
+        buffer.unsplit(message);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(input: &[u8]) -> BytesMut {
+        let mut buffer = BytesMut::from(input);
+        OctetCountingEncoder::new().encode((), &mut buffer).unwrap();
+        buffer
+    }
+
+    #[test]
+    fn prefixes_message_with_its_byte_length() {
+        assert_eq!(encode(b"hello world"), &b"11 hello world"[..]);
+    }
+
+    #[test]
+    fn empty_payload_encodes_to_zero_length_prefix() {
+        assert_eq!(encode(b""), &b"0 "[..]);
+    }
+
+    #[test]
+    fn length_is_the_byte_count_not_the_char_count() {
+        // "café" is 5 bytes but 4 chars; the prefix must reflect the byte count.
+        assert_eq!(encode("café".as_bytes()), [&b"5 "[..], "café".as_bytes()].concat());
+    }
+}
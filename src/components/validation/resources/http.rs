@@ -0,0 +1,313 @@
+use std::{net::SocketAddr, sync::Arc};
+
+use bytes::{Bytes, BytesMut};
+use hyper::{
+    service::{make_service_fn, service_fn},
+    Body, Client, Request, Response, Server, Uri,
+};
+use tokio::sync::{mpsc, Mutex, Notify};
+use tokio_util::codec::{Decoder, Encoder as _};
+use vector_core::event::Event;
+
+use super::{
+    classify_decode_error, compress, decompress, DecodeMetrics, FrameDecodeOutcome, ResourceCodec,
+    ResourceDirection, TestEvent,
+};
+use crate::components::validation::sync::{Configuring, TaskCoordinator};
+
+/// An HTTP endpoint, used as an external resource.
+///
+/// Each event is carried as the whole body of one request or response, at the configured `path`,
+/// so this resource is responsible for standing up the server side (or making the client calls) of
+/// that exchange, on top of whichever role -- source input or sink output -- it's being used for.
+#[derive(Clone, Debug)]
+pub struct HttpConfig {
+    address: SocketAddr,
+    path: String,
+}
+
+impl HttpConfig {
+    /// Creates a new `HttpConfig` for the given address, using `/` as the request path.
+    pub fn new(address: SocketAddr) -> Self {
+        Self {
+            address,
+            path: "/".to_string(),
+        }
+    }
+
+    /// Sets the path that requests are made to, or expected on.
+    pub fn with_path(mut self, path: impl Into<String>) -> Self {
+        self.path = path.into();
+        self
+    }
+
+    fn uri(&self) -> Uri {
+        format!("http://{}{}", self.address, self.path)
+            .parse()
+            .expect("constructed URI should always be valid")
+    }
+
+    /// Spawns this resource for use as an input to a source.
+    pub fn spawn_as_input(
+        self,
+        direction: ResourceDirection,
+        codec: ResourceCodec,
+        input_rx: mpsc::Receiver<TestEvent>,
+        task_coordinator: &TaskCoordinator<Configuring>,
+    ) {
+        let started = task_coordinator.track_started();
+        let completed = task_coordinator.track_completed();
+
+        tokio::spawn(async move {
+            started.mark_as_done();
+
+            match direction {
+                // The source connects to us, so we serve each request with the next encoded
+                // event.
+                ResourceDirection::Pull => {
+                    serve_input(self.address, self.path, codec, input_rx).await;
+                }
+                // The source listens for us, so we connect out and push each event as a request.
+                ResourceDirection::Push => {
+                    push_input(self.uri(), codec, input_rx).await;
+                }
+            }
+
+            completed.mark_as_done();
+        });
+    }
+
+    /// Spawns this resource for use as an output for a sink.
+    pub fn spawn_as_output(
+        self,
+        direction: ResourceDirection,
+        codec: ResourceCodec,
+        output_tx: mpsc::Sender<Event>,
+        task_coordinator: &TaskCoordinator<Configuring>,
+        decode_metrics: DecodeMetrics,
+    ) {
+        let started = task_coordinator.track_started();
+        let completed = task_coordinator.track_completed();
+
+        tokio::spawn(async move {
+            started.mark_as_done();
+
+            match direction {
+                // The sink exposes the endpoint, so we connect out and pull its response back.
+                ResourceDirection::Pull => {
+                    pull_output(self.uri(), codec, output_tx, decode_metrics).await;
+                }
+                // The sink connects to us, so we serve its requests and decode the bodies it
+                // sends.
+                ResourceDirection::Push => {
+                    serve_output(self.address, self.path, codec, output_tx, decode_metrics).await;
+                }
+            }
+
+            completed.mark_as_done();
+        });
+    }
+}
+
+/// Encodes a single event with `codec`, applying compression if configured.
+async fn encode_event(codec: &ResourceCodec, event: Event) -> Bytes {
+    let mut buffer = BytesMut::new();
+    codec
+        .into_encoder()
+        .encode(event, &mut buffer)
+        .expect("encoding event should not fail");
+
+    match codec.compression() {
+        Some(compression) => compress(compression, buffer.to_vec()).await.into(),
+        None => buffer.freeze(),
+    }
+}
+
+/// Decodes a request/response body with `codec`, forwarding every decoded frame to `output_tx` and
+/// recording the outcome of each in `decode_metrics`. Decompresses first, if configured.
+async fn decode_body(
+    codec: &ResourceCodec,
+    decode_metrics: &DecodeMetrics,
+    body: Bytes,
+) -> Vec<Event> {
+    let body = match codec.compression() {
+        Some(compression) => decompress(compression, body.to_vec()).await,
+        None => body.to_vec(),
+    };
+
+    let mut buffer = BytesMut::from(&body[..]);
+    let mut decoder = codec.into_decode_framer();
+    let mut events = Vec::new();
+
+    loop {
+        match decoder.decode_eof(&mut buffer) {
+            Ok(Some(frame)) => {
+                decode_metrics.record(FrameDecodeOutcome::Valid);
+                events.push(Event::from(frame.freeze()));
+            }
+            Ok(None) => break,
+            Err(error) => {
+                decode_metrics.record(classify_decode_error(&error));
+                break;
+            }
+        }
+    }
+
+    events
+}
+
+/// Serves requests by responding to each with the next queued input event, until `input_rx` is
+/// closed.
+async fn serve_input(
+    address: SocketAddr,
+    path: String,
+    codec: ResourceCodec,
+    input_rx: mpsc::Receiver<TestEvent>,
+) {
+    let input_rx = Arc::new(Mutex::new(input_rx));
+    let input_closed = Arc::new(Notify::new());
+    let shutdown = Arc::clone(&input_closed);
+
+    let make_service = make_service_fn(move |_| {
+        let codec = codec.clone();
+        let path = path.clone();
+        let input_rx = Arc::clone(&input_rx);
+        let input_closed = Arc::clone(&input_closed);
+
+        async move {
+            Ok::<_, std::convert::Infallible>(service_fn(move |request: Request<Body>| {
+                let codec = codec.clone();
+                let path = path.clone();
+                let input_rx = Arc::clone(&input_rx);
+                let input_closed = Arc::clone(&input_closed);
+
+                async move {
+                    if request.uri().path() != path.as_str() {
+                        return Ok::<_, std::convert::Infallible>(
+                            Response::builder()
+                                .status(hyper::StatusCode::NOT_FOUND)
+                                .body(Body::empty())
+                                .expect("building response should not fail"),
+                        );
+                    }
+
+                    let next_input = input_rx.lock().await.recv().await;
+                    let response = match next_input {
+                        Some(input) => {
+                            let body = encode_event(&codec, input.into_event()).await;
+                            Response::new(Body::from(body))
+                        }
+                        None => {
+                            input_closed.notify_one();
+                            Response::builder()
+                                .status(hyper::StatusCode::GONE)
+                                .body(Body::empty())
+                                .expect("building response should not fail")
+                        }
+                    };
+
+                    Ok(response)
+                }
+            }))
+        }
+    });
+
+    Server::bind(&address)
+        .serve(make_service)
+        .with_graceful_shutdown(async move { shutdown.notified().await })
+        .await
+        .expect("HTTP server should not fail");
+}
+
+/// Sends each queued input event as a request, until `input_rx` is closed.
+async fn push_input(uri: Uri, codec: ResourceCodec, mut input_rx: mpsc::Receiver<TestEvent>) {
+    let client = Client::new();
+
+    while let Some(input) = input_rx.recv().await {
+        let body = encode_event(&codec, input.into_event()).await;
+        let request = Request::post(&uri)
+            .body(Body::from(body))
+            .expect("building request should not fail");
+
+        client
+            .request(request)
+            .await
+            .expect("sending request should not fail");
+    }
+}
+
+/// Repeatedly requests the sink's exposed endpoint, decoding and forwarding each response body
+/// until a request fails.
+async fn pull_output(
+    uri: Uri,
+    codec: ResourceCodec,
+    output_tx: mpsc::Sender<Event>,
+    decode_metrics: DecodeMetrics,
+) {
+    let client = Client::new();
+
+    loop {
+        let Ok(response) = client.get(uri.clone()).await else {
+            break;
+        };
+
+        let Ok(body) = hyper::body::to_bytes(response.into_body()).await else {
+            break;
+        };
+
+        for event in decode_body(&codec, &decode_metrics, body).await {
+            let _ = output_tx.send(event).await;
+        }
+    }
+}
+
+/// Serves requests from the sink, decoding and forwarding each request body's events.
+async fn serve_output(
+    address: SocketAddr,
+    path: String,
+    codec: ResourceCodec,
+    output_tx: mpsc::Sender<Event>,
+    decode_metrics: DecodeMetrics,
+) {
+    let make_service = make_service_fn(move |_| {
+        let codec = codec.clone();
+        let path = path.clone();
+        let output_tx = output_tx.clone();
+        let decode_metrics = decode_metrics.clone();
+
+        async move {
+            Ok::<_, std::convert::Infallible>(service_fn(move |request: Request<Body>| {
+                let codec = codec.clone();
+                let path = path.clone();
+                let output_tx = output_tx.clone();
+                let decode_metrics = decode_metrics.clone();
+
+                async move {
+                    if request.uri().path() != path.as_str() {
+                        return Ok::<_, std::convert::Infallible>(
+                            Response::builder()
+                                .status(hyper::StatusCode::NOT_FOUND)
+                                .body(Body::empty())
+                                .expect("building response should not fail"),
+                        );
+                    }
+
+                    let body = hyper::body::to_bytes(request.into_body())
+                        .await
+                        .expect("reading request body should not fail");
+
+                    for event in decode_body(&codec, &decode_metrics, body).await {
+                        let _ = output_tx.send(event).await;
+                    }
+
+                    Ok(Response::new(Body::empty()))
+                }
+            }))
+        }
+    });
+
+    Server::bind(&address)
+        .serve(make_service)
+        .await
+        .expect("HTTP server should not fail");
+}
@@ -1,5 +1,11 @@
 mod event;
 mod http;
+mod socket;
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
 
 use codecs::{
     decoding::{self, DeserializerConfig},
@@ -13,6 +19,7 @@ use crate::codecs::{DecodingConfig, Encoder, EncodingConfig, EncodingConfigWithF
 
 pub use self::event::TestEvent;
 pub use self::http::HttpConfig;
+pub use self::socket::{SocketConfig, SocketTlsConfig, SocketTransport};
 
 use super::sync::{Configuring, TaskCoordinator};
 
@@ -32,12 +39,12 @@ pub enum ResourceCodec {
     /// the encoding itself.
     ///
     /// Generally speaking, only sinks encode: going from `Event` to an encoded form.
-    Encoding(EncodingConfig),
+    Encoding(EncodingConfig, Option<CompressionConfig>),
 
     /// Component encodes events, with a specific framer.
     ///
     /// Generally speaking, only sinks encode: going from `Event` to an encoded form.
-    EncodingWithFraming(EncodingConfigWithFraming),
+    EncodingWithFraming(EncodingConfigWithFraming, Option<CompressionConfig>),
 
     /// Component decodes events.
     ///
@@ -45,12 +52,16 @@ pub enum ResourceCodec {
     /// the decoding itself.
     ///
     /// Generally speaking, only sources decode: going from an encoded form to `Event`.
-    Decoding(DecodingConfig),
+    Decoding(DecodingConfig, Option<CompressionConfig>),
 
     /// Component decodes events, with a specific framer.
     ///
     /// Generally speaking, only sources decode: going from an encoded form to `Event`.
-    DecodingWithFraming(DecodingConfig, decoding::FramingConfig),
+    DecodingWithFraming(
+        DecodingConfig,
+        decoding::FramingConfig,
+        Option<CompressionConfig>,
+    ),
 }
 
 impl ResourceCodec {
@@ -61,25 +72,53 @@ impl ResourceCodec {
     /// we're only generating event payloads that can be encoded/decoded for the given component.
     pub fn allowed_event_data_types(self) -> DataType {
         match self {
-            Self::Encoding(encoding) => encoding.config().input_type(),
-            Self::EncodingWithFraming(encoding) => encoding.config().1.input_type(),
-            Self::Decoding(decoding) | Self::DecodingWithFraming(decoding, _) => {
+            Self::Encoding(encoding, _) => encoding.config().input_type(),
+            Self::EncodingWithFraming(encoding, _) => encoding.config().1.input_type(),
+            Self::Decoding(decoding, _) | Self::DecodingWithFraming(decoding, _, _) => {
                 decoding.config().output_type()
             }
         }
     }
 
+    /// Sets the compression applied on top of this codec's serialized/framed bytes.
+    ///
+    /// This models the gzip/zlib/zstd compression that many real sources/sinks layer on top of
+    /// their serializer, so that compressed components can be exercised end-to-end.
+    pub fn with_compression(mut self, compression: CompressionConfig) -> Self {
+        let slot = match &mut self {
+            Self::Encoding(_, compression)
+            | Self::EncodingWithFraming(_, compression)
+            | Self::Decoding(_, compression)
+            | Self::DecodingWithFraming(_, _, compression) => compression,
+        };
+        *slot = Some(compression);
+        self
+    }
+
+    /// Gets the compression configured for this codec, if any.
+    pub fn compression(&self) -> Option<CompressionConfig> {
+        match self {
+            Self::Encoding(_, compression)
+            | Self::EncodingWithFraming(_, compression)
+            | Self::Decoding(_, compression)
+            | Self::DecodingWithFraming(_, _, compression) => *compression,
+        }
+    }
+
     /// Gets an encoder for this codec.
     ///
     /// The encoder is generated as an inverse to the input codec: if a decoding configuration was
     /// given, we generate an encoder that satisfies that decoding configuration, and vise versa.
+    ///
+    /// This does not include compression: compression is CPU-bound, so it's applied separately, on
+    /// a blocking thread pool, via [`compress`]/[`decompress`] around the bytes this encoder emits.
     pub fn into_encoder(&self) -> Encoder<encoding::Framer> {
         let (framer, serializer) = match self {
-            Self::Encoding(config) => (
+            Self::Encoding(config, _) => (
                 Framer::Bytes(BytesEncoder::new()),
                 config.build().expect("should not fail to build serializer"),
             ),
-            Self::EncodingWithFraming(config) => {
+            Self::EncodingWithFraming(config, _) => {
                 let (maybe_framing, serializer) = config.config();
                 (
                     maybe_framing
@@ -91,11 +130,11 @@ impl ResourceCodec {
                         .expect("building serializer should never fail"),
                 )
             }
-            Self::Decoding(config) => (
+            Self::Decoding(config, _) => (
                 decoder_framing_to_encoding_framer(&config.config().default_stream_framing()),
                 deserializer_config_to_serializer(config.config()),
             ),
-            Self::DecodingWithFraming(config, framing) => (
+            Self::DecodingWithFraming(config, framing, _) => (
                 decoder_framing_to_encoding_framer(framing),
                 deserializer_config_to_serializer(config.config()),
             ),
@@ -103,26 +142,136 @@ impl ResourceCodec {
 
         Encoder::<encoding::Framer>::new(framer, serializer)
     }
+
+    /// Gets a framer for splitting raw bytes emitted by this codec back into frames.
+    ///
+    /// For a sink's `Encoding`/`EncodingWithFraming` codec, this reverses the configured encoding
+    /// framing so the runner can split the sink's output back into frames. For a source's
+    /// `Decoding`/`DecodingWithFraming` codec, the configured decoding framing is used directly.
+    pub fn into_decode_framer(&self) -> decoding::Framer {
+        match self {
+            Self::Encoding(_, _) => decoding::FramingConfig::Bytes.build(),
+            Self::EncodingWithFraming(config, _) => {
+                let (maybe_framing, _) = config.config();
+                encoding_framing_to_decoder_framer(
+                    &maybe_framing.clone().unwrap_or(FramingConfig::Bytes),
+                )
+            }
+            Self::Decoding(config, _) => config.config().default_stream_framing().build(),
+            Self::DecodingWithFraming(_, framing, _) => framing.clone().build(),
+        }
+    }
 }
 
 impl From<EncodingConfig> for ResourceCodec {
     fn from(config: EncodingConfig) -> Self {
-        Self::Encoding(config)
+        Self::Encoding(config, None)
     }
 }
 
 impl From<EncodingConfigWithFraming> for ResourceCodec {
     fn from(config: EncodingConfigWithFraming) -> Self {
-        Self::EncodingWithFraming(config)
+        Self::EncodingWithFraming(config, None)
     }
 }
 
 impl From<DecodingConfig> for ResourceCodec {
     fn from(config: DecodingConfig) -> Self {
-        Self::Decoding(config)
+        Self::Decoding(config, None)
     }
 }
 
+/// Compression applied on top of a [`ResourceCodec`]'s serialized/framed bytes.
+///
+/// This mirrors the compression support found on real sinks/sources (gzip, zlib, zstd) so that
+/// component validation can exercise components which compress their output, or expect compressed
+/// input, rather than being limited to modeling the serializer and framer alone.
+#[derive(Copy, Clone, Debug)]
+pub enum CompressionConfig {
+    /// Gzip compression, at the given level (0-9).
+    Gzip { level: u32 },
+
+    /// Zlib compression, at the given level (0-9).
+    Zlib { level: u32 },
+
+    /// Zstandard compression, at the given level.
+    Zstd { level: i32 },
+}
+
+impl CompressionConfig {
+    fn compress(self, input: Vec<u8>) -> Vec<u8> {
+        use std::io::Write;
+
+        match self {
+            Self::Gzip { level } => {
+                let mut encoder =
+                    flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::new(level));
+                encoder
+                    .write_all(&input)
+                    .expect("in-memory write should never fail");
+                encoder
+                    .finish()
+                    .expect("in-memory compression should never fail")
+            }
+            Self::Zlib { level } => {
+                let mut encoder =
+                    flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::new(level));
+                encoder
+                    .write_all(&input)
+                    .expect("in-memory write should never fail");
+                encoder
+                    .finish()
+                    .expect("in-memory compression should never fail")
+            }
+            Self::Zstd { level } => {
+                zstd::encode_all(input.as_slice(), level).expect("in-memory compression should never fail")
+            }
+        }
+    }
+
+    fn decompress(self, input: Vec<u8>) -> Vec<u8> {
+        use std::io::Read;
+
+        match self {
+            Self::Gzip { .. } => {
+                let mut output = Vec::new();
+                flate2::read::GzDecoder::new(input.as_slice())
+                    .read_to_end(&mut output)
+                    .expect("in-memory decompression should never fail");
+                output
+            }
+            Self::Zlib { .. } => {
+                let mut output = Vec::new();
+                flate2::read::ZlibDecoder::new(input.as_slice())
+                    .read_to_end(&mut output)
+                    .expect("in-memory decompression should never fail");
+                output
+            }
+            Self::Zstd { .. } => {
+                zstd::decode_all(input.as_slice()).expect("in-memory decompression should never fail")
+            }
+        }
+    }
+}
+
+/// Compresses `input` on a blocking thread pool, per `compression`.
+///
+/// Compression is CPU-bound and would otherwise stall the async runtime that drives the HTTP
+/// server/client in `http.rs`, so this mirrors how a response body is compressed off the event
+/// loop rather than inline.
+pub async fn compress(compression: CompressionConfig, input: Vec<u8>) -> Vec<u8> {
+    tokio::task::spawn_blocking(move || compression.compress(input))
+        .await
+        .expect("compression task should not panic")
+}
+
+/// Decompresses `input` on a blocking thread pool, per `compression`. See [`compress`].
+pub async fn decompress(compression: CompressionConfig, input: Vec<u8>) -> Vec<u8> {
+    tokio::task::spawn_blocking(move || compression.decompress(input))
+        .await
+        .expect("decompression task should not panic")
+}
+
 fn deserializer_config_to_serializer(config: &DeserializerConfig) -> encoding::Serializer {
     let serializer_config = match config {
         // TODO: This isn't necessarily a one-to-one conversion, at least not in the future when
@@ -159,14 +308,133 @@ fn decoder_framing_to_encoding_framer(framing: &decoding::FramingConfig) -> enco
         decoding::FramingConfig::NewlineDelimited { .. } => {
             encoding::FramingConfig::NewlineDelimited
         }
-        // TODO: There's no equivalent octet counting framer for encoding... although
-        // there's no particular reason that would make it hard to write.
-        decoding::FramingConfig::OctetCounting { .. } => todo!(),
+        decoding::FramingConfig::OctetCounting { .. } => encoding::FramingConfig::OctetCounting,
+        // COBS is self-synchronizing: the 0x00 delimiter never appears inside an encoded frame,
+        // so a corrupt or truncated frame can't cause the decoder to lose its place in the stream.
+        decoding::FramingConfig::Cobs => encoding::FramingConfig::Cobs,
+    };
+
+    framing_config.build()
+}
+
+/// Gets the decoding-side framer matching an encoding-side framing configuration.
+///
+/// This is the mirror of [`decoder_framing_to_encoding_framer`]: given the framing a component
+/// uses to *write* frames (e.g. a sink's configured encoding), it produces the framer that reads
+/// those same frames back, so the validation runner can split a component's raw output into
+/// frames without needing to know the component's framing choice up front.
+fn encoding_framing_to_decoder_framer(framing: &encoding::FramingConfig) -> decoding::Framer {
+    let framing_config = match framing {
+        encoding::FramingConfig::Bytes => decoding::FramingConfig::Bytes,
+        encoding::FramingConfig::CharacterDelimited {
+            character_delimited,
+        } => decoding::FramingConfig::CharacterDelimited {
+            character_delimited: decoding::CharacterDelimitedDecoderOptions {
+                delimiter: character_delimited.delimiter,
+                max_length: None,
+            },
+        },
+        encoding::FramingConfig::LengthDelimited => decoding::FramingConfig::LengthDelimited,
+        encoding::FramingConfig::NewlineDelimited => decoding::FramingConfig::NewlineDelimited {
+            newline_delimited: decoding::NewlineDelimitedDecoderOptions::default(),
+        },
+        encoding::FramingConfig::OctetCounting => decoding::FramingConfig::OctetCounting {
+            octet_counting: decoding::OctetCountingDecoderOptions::default(),
+        },
+        encoding::FramingConfig::Cobs => decoding::FramingConfig::Cobs,
     };
 
     framing_config.build()
 }
 
+/// The outcome of attempting to decode a single frame of data read back from a component.
+///
+/// The validation runner can't always cleanly turn the bytes read back from a component (the
+/// `spawn_as_output` path feeding `mpsc::Sender<Event>`) into an `Event` -- the stream might be
+/// truncated mid-frame, or the frame might simply be malformed -- and silently dropping those
+/// cases makes a genuine encoding bug look identical to a transient partial read. This type makes
+/// the distinction explicit so it can be counted, via [`DecodeMetrics`], and asserted on.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FrameDecodeOutcome {
+    /// The frame decoded successfully.
+    Valid,
+
+    /// The frame decoded, but failed a checksum or other integrity check.
+    ChecksumMismatch,
+
+    /// Not enough bytes were available yet to decode a full frame.
+    Truncated,
+
+    /// The bytes could not be interpreted as a frame at all.
+    Invalid,
+}
+
+/// Classifies a decoding error, returned by a [`decoding::Framer`], into a [`FrameDecodeOutcome`].
+///
+/// Every framer in the `codecs` crate reports a frame that's still missing bytes at end-of-stream
+/// as `ErrorKind::UnexpectedEof` (rather than `decode_eof`'s default, less specific error), so that
+/// kind is read back here as [`FrameDecodeOutcome::Truncated`]; anything else means the bytes
+/// buffered so far just aren't valid for the configured framing.
+///
+/// None of the framers this module supports today produce a distinct checksum-error type, so
+/// [`FrameDecodeOutcome::ChecksumMismatch`] is never returned here -- it's reserved for a framer
+/// that validates an integrity check and exposes that as its own error variant, rather than being
+/// guessed at from an error's display text.
+fn classify_decode_error(error: &std::io::Error) -> FrameDecodeOutcome {
+    match error.kind() {
+        std::io::ErrorKind::UnexpectedEof => FrameDecodeOutcome::Truncated,
+        _ => FrameDecodeOutcome::Invalid,
+    }
+}
+
+/// Running counts of [`FrameDecodeOutcome`]s observed while decoding data read back from a
+/// component.
+///
+/// Cloning a `DecodeMetrics` shares the same underlying counters, so the same handle can be held
+/// by both the task performing the decoding and the caller that later asserts on the resulting
+/// counts via [`TaskCoordinator`] completion, e.g. "zero truncated frames" or "N invalid frames
+/// expected".
+#[derive(Clone, Debug, Default)]
+pub struct DecodeMetrics {
+    valid: Arc<AtomicUsize>,
+    checksum_mismatch: Arc<AtomicUsize>,
+    truncated: Arc<AtomicUsize>,
+    invalid: Arc<AtomicUsize>,
+}
+
+impl DecodeMetrics {
+    /// Records a single [`FrameDecodeOutcome`].
+    pub fn record(&self, outcome: FrameDecodeOutcome) {
+        let counter = match outcome {
+            FrameDecodeOutcome::Valid => &self.valid,
+            FrameDecodeOutcome::ChecksumMismatch => &self.checksum_mismatch,
+            FrameDecodeOutcome::Truncated => &self.truncated,
+            FrameDecodeOutcome::Invalid => &self.invalid,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// The number of frames that decoded successfully.
+    pub fn valid(&self) -> usize {
+        self.valid.load(Ordering::Relaxed)
+    }
+
+    /// The number of frames that decoded, but failed a checksum or other integrity check.
+    pub fn checksum_mismatch(&self) -> usize {
+        self.checksum_mismatch.load(Ordering::Relaxed)
+    }
+
+    /// The number of times decoding ran out of bytes before it could complete a frame.
+    pub fn truncated(&self) -> usize {
+        self.truncated.load(Ordering::Relaxed)
+    }
+
+    /// The number of frames whose bytes could not be interpreted at all.
+    pub fn invalid(&self) -> usize {
+        self.invalid.load(Ordering::Relaxed)
+    }
+}
+
 /// Direction that the resource is operating in.
 pub enum ResourceDirection {
     /// Resource will have the component pull data from it, or pull data from the component.
@@ -202,6 +470,7 @@ pub enum ResourceDirection {
 /// specified an HTTP resource in the "pull" direction.
 pub enum ResourceDefinition {
     Http(HttpConfig),
+    Socket(SocketConfig),
 }
 
 impl From<HttpConfig> for ResourceDefinition {
@@ -210,6 +479,12 @@ impl From<HttpConfig> for ResourceDefinition {
     }
 }
 
+impl From<SocketConfig> for ResourceDefinition {
+    fn from(config: SocketConfig) -> Self {
+        Self::Socket(config)
+    }
+}
+
 /// An external resource associated with a component.
 ///
 /// External resources represent the hypothetical location where, depending on whether the component
@@ -228,6 +503,7 @@ pub struct ExternalResource {
     direction: ResourceDirection,
     definition: ResourceDefinition,
     codec: ResourceCodec,
+    decode_metrics: DecodeMetrics,
 }
 
 impl ExternalResource {
@@ -241,9 +517,18 @@ impl ExternalResource {
             direction,
             definition: definition.into(),
             codec: codec.into(),
+            decode_metrics: DecodeMetrics::default(),
         }
     }
 
+    /// Gets the [`DecodeMetrics`] for this resource.
+    ///
+    /// These counters are updated as data read back from the component (via `spawn_as_output`) is
+    /// decoded, and can be inspected once the associated `TaskCoordinator` reports completion.
+    pub fn decode_metrics(&self) -> DecodeMetrics {
+        self.decode_metrics.clone()
+    }
+
     /// Spawns this resource for use as an input to a source.
     pub fn spawn_as_input(
         self,
@@ -254,6 +539,12 @@ impl ExternalResource {
             ResourceDefinition::Http(http_config) => {
                 http_config.spawn_as_input(self.direction, self.codec, input_rx, task_coordinator)
             }
+            ResourceDefinition::Socket(socket_config) => socket_config.spawn_as_input(
+                self.direction,
+                self.codec,
+                input_rx,
+                task_coordinator,
+            ),
         }
     }
 
@@ -264,9 +555,94 @@ impl ExternalResource {
         task_coordinator: &TaskCoordinator<Configuring>,
     ) {
         match self.definition {
-            ResourceDefinition::Http(http_config) => {
-                http_config.spawn_as_output(self.direction, self.codec, output_tx, task_coordinator)
-            }
+            ResourceDefinition::Http(http_config) => http_config.spawn_as_output(
+                self.direction,
+                self.codec,
+                output_tx,
+                task_coordinator,
+                self.decode_metrics,
+            ),
+            ResourceDefinition::Socket(socket_config) => socket_config.spawn_as_output(
+                self.direction,
+                self.codec,
+                output_tx,
+                task_coordinator,
+                self.decode_metrics,
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::BytesMut;
+    use tokio_util::codec::Decoder as _;
+
+    #[test]
+    fn compression_round_trips_through_each_algorithm() {
+        let input = b"the quick brown fox jumps over the lazy dog".to_vec();
+
+        for compression in [
+            CompressionConfig::Gzip { level: 6 },
+            CompressionConfig::Zlib { level: 6 },
+            CompressionConfig::Zstd { level: 3 },
+        ] {
+            let compressed = compression.compress(input.clone());
+            assert_ne!(compressed, input, "{compression:?} should actually transform the bytes");
+            assert_eq!(compression.decompress(compressed), input);
+        }
+    }
+
+    #[test]
+    fn classify_decode_error_reports_truncated_for_a_short_frame_at_eof() {
+        // Drive a real framer's `decode_eof` with bytes that end mid-frame, rather than
+        // constructing a `FrameDecodeOutcome` directly -- this is the exact "connection closed
+        // partway through a frame" scenario this classification exists to catch.
+        let mut framer = decoding::FramingConfig::OctetCounting {
+            octet_counting: decoding::OctetCountingDecoderOptions::default(),
+        }
+        .build();
+        let mut buffer = BytesMut::from(&b"5 hel"[..]);
+
+        let error = framer
+            .decode_eof(&mut buffer)
+            .expect_err("a frame missing bytes at EOF should be an error");
+        assert_eq!(classify_decode_error(&error), FrameDecodeOutcome::Truncated);
+    }
+
+    #[test]
+    fn classify_decode_error_reports_invalid_for_malformed_bytes() {
+        let mut framer = decoding::FramingConfig::OctetCounting {
+            octet_counting: decoding::OctetCountingDecoderOptions::default(),
         }
+        .build();
+        let mut buffer = BytesMut::from(&b"not a length "[..]);
+
+        let error = framer
+            .decode(&mut buffer)
+            .expect_err("a non-numeric octet count prefix should be an error");
+        assert_eq!(classify_decode_error(&error), FrameDecodeOutcome::Invalid);
+    }
+
+    #[test]
+    fn decode_metrics_counts_each_outcome_independently() {
+        let metrics = DecodeMetrics::default();
+        metrics.record(FrameDecodeOutcome::Valid);
+        metrics.record(FrameDecodeOutcome::Valid);
+        metrics.record(FrameDecodeOutcome::Truncated);
+        metrics.record(FrameDecodeOutcome::Invalid);
+
+        assert_eq!(metrics.valid(), 2);
+        assert_eq!(metrics.truncated(), 1);
+        assert_eq!(metrics.invalid(), 1);
+        assert_eq!(metrics.checksum_mismatch(), 0);
+    }
+
+    #[tokio::test]
+    async fn compress_and_decompress_run_off_the_async_runtime() {
+        let input = b"hello from the blocking pool".to_vec();
+        let compressed = compress(CompressionConfig::Zstd { level: 3 }, input.clone()).await;
+        assert_eq!(decompress(CompressionConfig::Zstd { level: 3 }, compressed).await, input);
     }
 }